@@ -1,3 +1,4 @@
+use ripemd::Ripemd160;
 use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use sha2::{Digest, Sha256};
@@ -13,39 +14,380 @@ pub enum AddressError {
   InvalidLength(usize),
   #[error("Expected the first character to be '1', found: {0}")]
   InvalidStartingCharacter(char),
+  #[error("Invalid Base58 character: {0}")]
+  InvalidCharacter(char),
+  #[error("Checksum verification failed")]
+  InvalidChecksum,
+  #[error("Expected a 25-byte payload, found: {0}")]
+  InvalidPayloadLength(usize),
+  #[cfg(feature = "secp256k1")]
+  #[error("Invalid base64 signature: {0}")]
+  InvalidSignatureEncoding(#[from] base64::DecodeError),
+  #[cfg(feature = "secp256k1")]
+  #[error("Expected a 65-byte signature, found: {0}")]
+  InvalidSignatureLength(usize),
+  #[cfg(feature = "secp256k1")]
+  #[error("Invalid recovery header byte: {0}")]
+  InvalidRecoveryHeader(u8),
+  #[cfg(feature = "secp256k1")]
+  #[error("Invalid signature: {0}")]
+  InvalidSignature(#[from] secp256k1::Error),
+  #[error("Unknown wire encoding tag: {0}")]
+  InvalidTag(u8),
+  #[error("Expected a {0}-byte encoded buffer, found: {1}")]
+  InvalidEncodedLength(usize, usize),
+  #[error("Encoded domain is not valid UTF-8: {0}")]
+  InvalidDomainEncoding(#[from] std::str::Utf8Error),
+  #[error("Encoded domain is not a registered ZeroNet domain: {0}")]
+  InvalidDomain(String),
+}
+
+const BASE58_ALPHABET: &[u8; 58] =
+  b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+// Tags for the compact binary wire encoding, see `Address::write_to`.
+const WIRE_TAG_KEY: u8 = 0;
+const WIRE_TAG_DOMAIN: u8 = 1;
+const WIRE_TAG_TEST: u8 = 2;
+
+// Namecoin-backed TLDs ZeroNet recognizes as domain addresses, as opposed to
+// raw Base58 site keys.
+const ZERONET_DOMAIN_TLDS: &[&str] = &[".bit"];
+
+// Returns true if `s` looks like a registered ZeroNet domain name (e.g. a
+// `.bit` Namecoin domain) rather than a Base58 site key.
+fn is_domain(s: &str) -> bool {
+  ZERONET_DOMAIN_TLDS.iter().any(|tld| s.ends_with(tld))
+    && !s.starts_with('.')
+    && !s.starts_with('-')
+    && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
 }
 
 #[derive(Hash, PartialEq, Eq, Debug, Clone)]
-pub struct Address(pub String);
+pub enum Address {
+  // A raw Base58Check-encoded ZeroNet site key, e.g. `1HeLLo...`.
+  Key(String),
+  // A `.bit` (or other registered TLD) Namecoin domain that resolves to a key.
+  Domain(String),
+}
 
 impl Address {
+  fn as_str(&self) -> &str {
+    match self {
+      Address::Key(s) => s,
+      Address::Domain(s) => s,
+    }
+  }
+
+  // Returns the underlying Base58 key address when this address already is
+  // one, so callers can hash or validate it without resolving the domain.
+  pub fn resolved_key(&self) -> Option<Address> {
+    match self {
+      Address::Key(_) => Some(self.clone()),
+      Address::Domain(_) => None,
+    }
+  }
+
   // Returns the digest of the SHA256 hash of the ASCII encoding
   pub fn get_address_hash(&self) -> Vec<u8> {
     let mut hasher = Sha256::default();
-    hasher.update(&self.0);
+    hasher.update(self.as_str());
     hasher.finalize().to_vec()
   }
 
   // Returns the digest of the SHA1 hash of the ACII encoding
   pub fn get_address_sha1(&self) -> String {
-    self.0.clone()
+    self.as_str().to_string()
   }
 
-  // Returns the first 6 and last 4 characters of address
+  // Returns the first 6 and last 5 characters of a Key address. Domain
+  // addresses are already short and human-readable, so they're returned
+  // unshortened.
   pub fn get_address_short(&self) -> String {
-    if self.0.as_str() == "Test" {
-      return self.0.clone();
+    let s = match self {
+      Address::Domain(domain) => return domain.clone(),
+      Address::Key(s) => s,
+    };
+    if s == "Test" || s.len() < 11 {
+      return s.clone();
     }
-    let l = self.0.len();
-    let f = self.0.get(0..6).unwrap();
-    let b = self.0.get(l - 5..l).unwrap();
+    let l = s.len();
+    let f = s.get(0..6).unwrap();
+    let b = s.get(l - 5..l).unwrap();
     format!("{}...{}", f, b)
   }
+
+  // Decodes the Base58Check string, verifies the checksum and returns the
+  // version byte together with the 20-byte HASH160 payload.
+  pub fn decode(&self) -> Result<(u8, [u8; 20]), AddressError> {
+    let s = self.as_str();
+
+    let mut leading_zeros = 0;
+    for c in s.chars() {
+      if c == '1' {
+        leading_zeros += 1;
+      } else {
+        break;
+      }
+    }
+
+    let mut bytes: Vec<u8> = vec![0];
+    for c in s.chars() {
+      let digit = BASE58_ALPHABET
+        .iter()
+        .position(|&b| b == c as u8)
+        .ok_or(AddressError::InvalidCharacter(c))? as u32;
+
+      let mut carry = digit;
+      for byte in bytes.iter_mut().rev() {
+        carry += *byte as u32 * 58;
+        *byte = (carry & 0xff) as u8;
+        carry >>= 8;
+      }
+      while carry > 0 {
+        bytes.insert(0, (carry & 0xff) as u8);
+        carry >>= 8;
+      }
+    }
+
+    let leading_ones = bytes.iter().take_while(|&&b| b == 0).count();
+    let mut decoded: Vec<u8> = vec![0; leading_zeros];
+    decoded.extend_from_slice(&bytes[leading_ones..]);
+
+    if decoded.len() != 25 {
+      return Err(AddressError::InvalidPayloadLength(decoded.len()));
+    }
+
+    let version = decoded[0];
+    let payload = &decoded[1..21];
+    let checksum = &decoded[21..25];
+
+    let mut hasher = Sha256::default();
+    hasher.update(&decoded[0..21]);
+    let first_hash = hasher.finalize();
+    let mut hasher = Sha256::default();
+    hasher.update(first_hash);
+    let second_hash = hasher.finalize();
+
+    if &second_hash[0..4] != checksum {
+      return Err(AddressError::InvalidChecksum);
+    }
+
+    let mut hash160 = [0u8; 20];
+    hash160.copy_from_slice(payload);
+    Ok((version, hash160))
+  }
+
+  // Builds the canonical Base58Check address for a given version byte and
+  // HASH160 payload, the inverse of `decode`.
+  pub fn from_hash160(version: u8, hash: &[u8; 20]) -> Address {
+    let mut payload = Vec::with_capacity(25);
+    payload.push(version);
+    payload.extend_from_slice(hash);
+
+    let mut hasher = Sha256::default();
+    hasher.update(&payload);
+    let first_hash = hasher.finalize();
+    let mut hasher = Sha256::default();
+    hasher.update(first_hash);
+    let second_hash = hasher.finalize();
+    payload.extend_from_slice(&second_hash[0..4]);
+
+    Address::Key(base58_encode(&payload))
+  }
+
+  // Hashes a SEC-encoded public key with SHA256 then RIPEMD160 and encodes
+  // the result as an address, as ZeroNet site keys do.
+  pub fn from_public_key(version: u8, public_key: &[u8]) -> Address {
+    let mut sha256 = Sha256::default();
+    sha256.update(public_key);
+    let sha256_digest = sha256.finalize();
+
+    let mut ripemd160 = Ripemd160::default();
+    ripemd160.update(sha256_digest);
+    let mut hash = [0u8; 20];
+    hash.copy_from_slice(&ripemd160.finalize());
+
+    Address::from_hash160(version, &hash)
+  }
+
+  // Serializes this address into a compact, self-describing binary form for
+  // peer protocol frames and on-disk storage: a tag byte followed by the
+  // 21-byte `[version][hash160]` payload for a key, the UTF-8 domain bytes
+  // for a domain, or nothing at all for the `Test` sentinel.
+  pub fn write_to(&self) -> Result<Vec<u8>, AddressError> {
+    match self {
+      Address::Key(s) if s == "Test" => Ok(vec![WIRE_TAG_TEST]),
+      Address::Key(_) => {
+        let (version, hash160) = self.decode()?;
+        let mut buf = Vec::with_capacity(22);
+        buf.push(WIRE_TAG_KEY);
+        buf.push(version);
+        buf.extend_from_slice(&hash160);
+        Ok(buf)
+      }
+      Address::Domain(domain) => {
+        let mut buf = Vec::with_capacity(1 + domain.len());
+        buf.push(WIRE_TAG_DOMAIN);
+        buf.extend_from_slice(domain.as_bytes());
+        Ok(buf)
+      }
+    }
+  }
+
+  // Reconstructs an `Address` from the binary form produced by `write_to`.
+  pub fn read_from(buf: &[u8]) -> Result<Address, AddressError> {
+    let (&tag, rest) = buf
+      .split_first()
+      .ok_or(AddressError::InvalidEncodedLength(1, 0))?;
+
+    match tag {
+      WIRE_TAG_TEST => {
+        if !rest.is_empty() {
+          return Err(AddressError::InvalidEncodedLength(1, buf.len()));
+        }
+        Ok(Address::Key("Test".to_string()))
+      }
+      WIRE_TAG_KEY => {
+        if rest.len() != 21 {
+          return Err(AddressError::InvalidEncodedLength(22, buf.len()));
+        }
+        let version = rest[0];
+        let mut hash160 = [0u8; 20];
+        hash160.copy_from_slice(&rest[1..21]);
+        Ok(Address::from_hash160(version, &hash160))
+      }
+      WIRE_TAG_DOMAIN => {
+        let domain = std::str::from_utf8(rest)?;
+        if !is_domain(domain) {
+          return Err(AddressError::InvalidDomain(domain.to_string()));
+        }
+        Ok(Address::Domain(domain.to_string()))
+      }
+      _ => Err(AddressError::InvalidTag(tag)),
+    }
+  }
+
+  // Verifies a Bitcoin-style signed message (as used to sign ZeroNet
+  // `content.json` files) against this address.
+  #[cfg(feature = "secp256k1")]
+  pub fn verify(&self, message: &str, signature_base64: &str) -> Result<bool, AddressError> {
+    use base64::Engine;
+    use secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
+    use secp256k1::{Message, Secp256k1};
+
+    let signature = base64::engine::general_purpose::STANDARD.decode(signature_base64)?;
+    if signature.len() != 65 {
+      return Err(AddressError::InvalidSignatureLength(signature.len()));
+    }
+
+    let header = signature[0];
+    if !(27..=34).contains(&header) {
+      return Err(AddressError::InvalidRecoveryHeader(header));
+    }
+    let flag = header - 27;
+    let recid = RecoveryId::from_i32((flag & 3) as i32)?;
+    let compressed = flag & 4 != 0;
+
+    let recoverable_signature = RecoverableSignature::from_compact(&signature[1..65], recid)?;
+
+    let digest = bitcoin_signed_message_digest(message);
+    let msg = Message::from_slice(&digest)?;
+
+    let secp = Secp256k1::verification_only();
+    let public_key = secp.recover_ecdsa(&msg, &recoverable_signature)?;
+
+    let public_key_bytes = if compressed {
+      public_key.serialize().to_vec()
+    } else {
+      public_key.serialize_uncompressed().to_vec()
+    };
+
+    let mut sha256 = Sha256::default();
+    sha256.update(&public_key_bytes);
+    let sha256_digest = sha256.finalize();
+
+    let mut ripemd160 = Ripemd160::default();
+    ripemd160.update(sha256_digest);
+    let mut hash160 = [0u8; 20];
+    hash160.copy_from_slice(&ripemd160.finalize());
+
+    let recovered = Address::from_hash160(0x00, &hash160);
+    Ok(recovered == *self)
+  }
+}
+
+// Serializes the Bitcoin "varint" length prefix used by the signed message
+// format: values below 0xfd are a single byte, larger values are prefixed
+// with a marker byte followed by a fixed-width little-endian integer.
+#[cfg(feature = "secp256k1")]
+fn write_var_int(buf: &mut Vec<u8>, value: u64) {
+  if value < 0xfd {
+    buf.push(value as u8);
+  } else if value <= 0xffff {
+    buf.push(0xfd);
+    buf.extend_from_slice(&(value as u16).to_le_bytes());
+  } else if value <= 0xffff_ffff {
+    buf.push(0xfe);
+    buf.extend_from_slice(&(value as u32).to_le_bytes());
+  } else {
+    buf.push(0xff);
+    buf.extend_from_slice(&value.to_le_bytes());
+  }
+}
+
+// Builds the double-SHA256 digest of a Bitcoin signed message, prefixing it
+// with the magic "Bitcoin Signed Message:\n" header as specified by the
+// Bitcoin Core `signmessage`/`verifymessage` RPCs.
+#[cfg(feature = "secp256k1")]
+fn bitcoin_signed_message_digest(message: &str) -> [u8; 32] {
+  const MAGIC: &str = "Bitcoin Signed Message:\n";
+
+  let mut buf = Vec::new();
+  write_var_int(&mut buf, MAGIC.len() as u64);
+  buf.extend_from_slice(MAGIC.as_bytes());
+  write_var_int(&mut buf, message.len() as u64);
+  buf.extend_from_slice(message.as_bytes());
+
+  let mut hasher = Sha256::default();
+  hasher.update(&buf);
+  let first_hash = hasher.finalize();
+  let mut hasher = Sha256::default();
+  hasher.update(first_hash);
+  let second_hash = hasher.finalize();
+
+  let mut digest = [0u8; 32];
+  digest.copy_from_slice(&second_hash);
+  digest
+}
+
+// Base58-encodes a byte buffer big-endian, emitting one leading '1' per
+// leading zero byte, using the Bitcoin alphabet.
+fn base58_encode(input: &[u8]) -> String {
+  let leading_zeros = input.iter().take_while(|&&b| b == 0).count();
+
+  let mut digits: Vec<u8> = vec![0];
+  for &byte in input {
+    let mut carry = byte as u32;
+    for digit in digits.iter_mut() {
+      carry += (*digit as u32) << 8;
+      *digit = (carry % 58) as u8;
+      carry /= 58;
+    }
+    while carry > 0 {
+      digits.push((carry % 58) as u8);
+      carry /= 58;
+    }
+  }
+
+  let mut result: String = "1".repeat(leading_zeros);
+  result.extend(digits.iter().rev().map(|&d| BASE58_ALPHABET[d as usize] as char));
+  result
 }
 
 impl Display for Address {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    write!(f, "{}", self.0)
+    write!(f, "{}", self.as_str())
   }
 }
 
@@ -54,7 +396,7 @@ impl Serialize for Address {
   where
     S: Serializer,
   {
-    serializer.serialize_str(&self.0)
+    serializer.serialize_str(self.as_str())
   }
 }
 
@@ -64,20 +406,27 @@ impl<'de> Visitor<'de> for AddressVisitor {
   type Value = Address;
 
   fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-    formatter.write_str("a string between 26 and 34 characters starting with a '1'")
+    formatter.write_str("a Base58 ZeroNet site key or a registered domain name")
   }
 
   fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
   where
     E: serde::de::Error,
   {
+    if is_domain(v) {
+      return Ok(Address::Domain(v.to_string()));
+    }
+
     if v.len() > 34 || v.len() < 26 {
       return Err(E::custom("Expected length between 26 and 34 characters"));
     }
     if !v.starts_with('1') {
       return Err(E::custom("Address should start with '1'"));
     }
-    Address::from_str(v).map_err(|_| E::custom("invalid ZeroNet address"))
+    let address = Address::from_str(v).map_err(|_| E::custom("invalid ZeroNet address"))?;
+    address.decode().map_err(E::custom)?;
+
+    Ok(address)
   }
 }
 
@@ -94,11 +443,15 @@ impl FromStr for Address {
   type Err = AddressError;
 
   fn from_str(string: &str) -> Result<Address, AddressError> {
+    if is_domain(string) {
+      return Ok(Address::Domain(string.to_string()));
+    }
+
     let s = String::from(string);
 
     // "Test" is the only address invalid address allowed
     if string == "Test" {
-      return Ok(Address(String::from(string)));
+      return Ok(Address::Key(String::from(string)));
     }
 
     if s.len() > 34 || s.len() < 26 {
@@ -113,13 +466,19 @@ impl FromStr for Address {
       ));
     }
 
-    Ok(Address(String::from(string)))
+    let address = Address::Key(String::from(string));
+    address.decode()?;
+
+    Ok(address)
   }
 }
 
 impl Into<String> for Address {
   fn into(self) -> String {
-    self.0.clone()
+    match self {
+      Address::Key(s) => s,
+      Address::Domain(s) => s,
+    }
   }
 }
 
@@ -136,13 +495,21 @@ mod tests {
 
   #[test]
   fn test_from_str() {
-    let result = Address::from_str("1HeLLo4uzjaLetFx6NH3PMwFP3qbRbTf3D");
-    assert!(result.is_ok());
+    let result = Address::from_str("1BoatSLRHtKNngkdXEeobR76b53LETtpyT");
+    assert!(result.is_ok(), "Encountered error: {:?}", result);
+  }
+
+  #[test]
+  fn test_from_str_rejects_invalid_payload_by_default() {
+    // Right length, starts with '1', but not a valid Base58Check payload.
+    // Strict validation must run unconditionally, not behind a feature.
+    let result = Address::from_str("1111111111111111111111111x");
+    assert!(matches!(result, Err(AddressError::InvalidPayloadLength(_))));
   }
 
   #[test]
   fn test_serialization() {
-    let address = Address("1HeLLo4uzjaLetFx6NH3PMwFP3qbRbTf3D".to_string());
+    let address = Address::Key("1HeLLo4uzjaLetFx6NH3PMwFP3qbRbTf3D".to_string());
     let result = serde_json::to_string(&address);
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), "\"1HeLLo4uzjaLetFx6NH3PMwFP3qbRbTf3D\"");
@@ -150,21 +517,240 @@ mod tests {
 
   #[test]
   fn test_deserialization() {
-    let result = serde_json::from_str("\"1HeLLo4uzjaLetFx6NH3PMwFP3qbRbTf3D\"");
+    let result = serde_json::from_str("\"1BoatSLRHtKNngkdXEeobR76b53LETtpyT\"");
     assert!(result.is_ok(), "Encountered error: {:?}", result);
     let address: Address = result.unwrap();
     assert_eq!(
       address,
-      Address("1HeLLo4uzjaLetFx6NH3PMwFP3qbRbTf3D".to_string())
+      Address::Key("1BoatSLRHtKNngkdXEeobR76b53LETtpyT".to_string())
+    );
+  }
+
+  #[test]
+  fn test_deserialization_rejects_invalid_payload() {
+    let result: Result<Address, _> = serde_json::from_str("\"1111111111111111111111111x\"");
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn test_decode_valid_address() {
+    let address = Address::Key("1BoatSLRHtKNngkdXEeobR76b53LETtpyT".to_string());
+    let result = address.decode();
+    assert!(result.is_ok(), "Encountered error: {:?}", result);
+    let (version, hash160) = result.unwrap();
+    assert_eq!(version, 0x00);
+    assert_eq!(
+      hash160,
+      [
+        0x76, 0x80, 0xad, 0xec, 0x8e, 0xab, 0xca, 0xba, 0xc6, 0x76, 0xbe, 0x9e, 0x83, 0x85, 0x4a,
+        0xde, 0x0b, 0xd2, 0x2c, 0xdb
+      ]
+    );
+  }
+
+  #[test]
+  fn test_decode_invalid_checksum() {
+    let address = Address::Key("1BoatSLRHtKNngkdXEeobR76b53LETtpyX".to_string());
+    let result = address.decode();
+    assert!(matches!(result, Err(AddressError::InvalidChecksum)));
+  }
+
+  #[test]
+  fn test_decode_invalid_character() {
+    let address = Address::Key("1BoatSLRHtKNngkdXEeobR76b53LETt0yT".to_string());
+    let result = address.decode();
+    assert!(matches!(result, Err(AddressError::InvalidCharacter('0'))));
+  }
+
+  #[test]
+  fn test_from_hash160_round_trip() {
+    let address = Address::Key("1BoatSLRHtKNngkdXEeobR76b53LETtpyT".to_string());
+    let (version, hash160) = address.decode().unwrap();
+    let rebuilt = Address::from_hash160(version, &hash160);
+    assert_eq!(rebuilt, address);
+  }
+
+  #[test]
+  fn test_from_public_key() {
+    // SEC-compressed public key for the secp256k1 generator point G.
+    let public_key: [u8; 33] = [
+      0x02, 0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87,
+      0x0b, 0x07, 0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b, 0x16,
+      0xf8, 0x17, 0x98,
+    ];
+    let address = Address::from_public_key(0x00, &public_key);
+    assert_eq!(
+      address,
+      Address::Key("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH".to_string())
     );
   }
 
   #[test]
   fn test_sha1() {
-    let address = Address("1HeLLo4uzjaLetFx6NH3PMwFP3qbRbTf3D".to_string());
+    let address = Address::Key("1HeLLo4uzjaLetFx6NH3PMwFP3qbRbTf3D".to_string());
     assert_eq!(
       address.get_address_sha1(),
       "1HeLLo4uzjaLetFx6NH3PMwFP3qbRbTf3D".to_string()
     );
   }
+
+  #[test]
+  fn test_from_str_domain() {
+    let result = Address::from_str("example.bit");
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), Address::Domain("example.bit".to_string()));
+  }
+
+  #[test]
+  fn test_domain_display_and_serialization() {
+    let address = Address::Domain("example.bit".to_string());
+    assert_eq!(address.to_string(), "example.bit");
+    let result = serde_json::to_string(&address);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), "\"example.bit\"");
+  }
+
+  #[test]
+  fn test_domain_get_address_short_is_unshortened() {
+    // Longer than the 11-character threshold that triggers shortening for
+    // Key addresses; a domain must still come back untouched.
+    let address = Address::Domain("longer-domain.bit".to_string());
+    assert_eq!(address.get_address_short(), "longer-domain.bit");
+  }
+
+  #[test]
+  fn test_domain_deserialization() {
+    let result = serde_json::from_str("\"example.bit\"");
+    assert!(result.is_ok(), "Encountered error: {:?}", result);
+    let address: Address = result.unwrap();
+    assert_eq!(address, Address::Domain("example.bit".to_string()));
+  }
+
+  #[test]
+  fn test_write_read_round_trip_key() {
+    let address = Address::Key("1BoatSLRHtKNngkdXEeobR76b53LETtpyT".to_string());
+    let encoded = address.write_to().unwrap();
+    assert_eq!(encoded.len(), 22);
+    let decoded = Address::read_from(&encoded).unwrap();
+    assert_eq!(decoded, address);
+  }
+
+  #[test]
+  fn test_write_read_round_trip_domain() {
+    let address = Address::Domain("example.bit".to_string());
+    let encoded = address.write_to().unwrap();
+    let decoded = Address::read_from(&encoded).unwrap();
+    assert_eq!(decoded, address);
+  }
+
+  #[test]
+  fn test_write_read_round_trip_test_sentinel() {
+    let address = Address::Key("Test".to_string());
+    let encoded = address.write_to().unwrap();
+    assert_eq!(encoded, vec![2]);
+    let decoded = Address::read_from(&encoded).unwrap();
+    assert_eq!(decoded, address);
+  }
+
+  #[test]
+  fn test_read_from_invalid_tag() {
+    let result = Address::read_from(&[0xff, 0x00]);
+    assert!(matches!(result, Err(AddressError::InvalidTag(0xff))));
+  }
+
+  #[test]
+  fn test_read_from_rejects_non_domain_bytes() {
+    let result = Address::read_from(&[1]);
+    assert!(matches!(result, Err(AddressError::InvalidDomain(_))));
+
+    let result = Address::read_from(&[1, b'x']);
+    assert!(matches!(result, Err(AddressError::InvalidDomain(_))));
+  }
+
+  #[test]
+  fn test_read_from_rejects_trailing_test_bytes() {
+    let result = Address::read_from(&[2, 0xff, 0xff, 0xff]);
+    assert!(matches!(result, Err(AddressError::InvalidEncodedLength(1, 4))));
+  }
+
+  // Known-answer vector for `Address::verify`, generated with the
+  // `secp256k1` crate's own signer against a fixed, non-sensitive test-only
+  // private key (`[0x11; 32]`).
+  #[cfg(feature = "secp256k1")]
+  const VERIFY_ADDRESS: &str = "1Q1pE5vPGEEMqRcVRMbtBK842Y6Pzo6nK9";
+  #[cfg(feature = "secp256k1")]
+  const VERIFY_MESSAGE: &str = "ZeroNet test message";
+  #[cfg(feature = "secp256k1")]
+  const VERIFY_SIGNATURE: &str =
+    "IC/8KQZ2uDvfiW+30m0wPMICgXixtG0Ex2vD+ydLZ6F1UMHQiYZ0OtBt/NufCzvbZG1sawaTr+wLLE8r5IN8yeU=";
+
+  #[cfg(feature = "secp256k1")]
+  #[test]
+  fn test_verify_known_vector() {
+    let address = Address::Key(VERIFY_ADDRESS.to_string());
+    let result = address.verify(VERIFY_MESSAGE, VERIFY_SIGNATURE);
+    assert!(result.unwrap());
+  }
+
+  #[cfg(feature = "secp256k1")]
+  #[test]
+  fn test_verify_tampered_message() {
+    let address = Address::Key(VERIFY_ADDRESS.to_string());
+    let result = address.verify("not the signed message", VERIFY_SIGNATURE);
+    assert!(!result.unwrap());
+  }
+
+  #[cfg(feature = "secp256k1")]
+  #[test]
+  fn test_verify_mismatched_address() {
+    let address = Address::Key("1BoatSLRHtKNngkdXEeobR76b53LETtpyT".to_string());
+    let result = address.verify(VERIFY_MESSAGE, VERIFY_SIGNATURE);
+    assert!(!result.unwrap());
+  }
+
+  #[cfg(feature = "secp256k1")]
+  #[test]
+  fn test_verify_invalid_base64() {
+    let address = Address::Key(VERIFY_ADDRESS.to_string());
+    let result = address.verify(VERIFY_MESSAGE, "not valid base64!!");
+    assert!(matches!(
+      result,
+      Err(AddressError::InvalidSignatureEncoding(_))
+    ));
+  }
+
+  #[cfg(feature = "secp256k1")]
+  #[test]
+  fn test_verify_truncated_signature() {
+    let address = Address::Key(VERIFY_ADDRESS.to_string());
+    // Only the header byte and the first 39 bytes of r||s.
+    let truncated = "IC/8KQZ2uDvfiW+30m0wPMICgXixtG0Ex2vD+ydLZ6F1UMHQiYZ0Og==";
+    let result = address.verify(VERIFY_MESSAGE, truncated);
+    assert!(matches!(
+      result,
+      Err(AddressError::InvalidSignatureLength(40))
+    ));
+  }
+
+  #[cfg(feature = "secp256k1")]
+  #[test]
+  fn test_verify_invalid_recovery_header() {
+    let address = Address::Key(VERIFY_ADDRESS.to_string());
+    // Same signature bytes, header byte replaced with 0 (outside 27..=34).
+    let bad_header = "AC/8KQZ2uDvfiW+30m0wPMICgXixtG0Ex2vD+ydLZ6F1UMHQiYZ0OtBt/NufCzvbZG1sawaTr+wLLE8r5IN8yeU=";
+    let result = address.verify(VERIFY_MESSAGE, bad_header);
+    assert!(matches!(
+      result,
+      Err(AddressError::InvalidRecoveryHeader(0))
+    ));
+  }
+
+  #[test]
+  fn test_resolved_key() {
+    let key = Address::Key("1HeLLo4uzjaLetFx6NH3PMwFP3qbRbTf3D".to_string());
+    assert_eq!(key.resolved_key(), Some(key.clone()));
+
+    let domain = Address::Domain("example.bit".to_string());
+    assert_eq!(domain.resolved_key(), None);
+  }
 }
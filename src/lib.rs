@@ -0,0 +1,3 @@
+mod address;
+
+pub use address::{Address, AddressError};